@@ -0,0 +1,100 @@
+//! [`std::io`] integration for reading and writing netstrings directly over
+//! sockets, files, and other [`Read`]/[`Write`] implementations.
+
+use std::io::{self, Read, Write};
+
+use crate::{NetstringEncoder, NetstringError, NetstringParser};
+
+/// Reads netstrings from an underlying [`Read`] implementation.
+///
+/// Wraps a [`NetstringParser`] and drives its `available_buffer`/`advance`/`parse_next`
+/// loop for you, similar to how [`std::io::BufReader`] fills and drains its own
+/// internal buffer.
+#[derive(Debug)]
+pub struct NetstringReader<R> {
+    inner: R,
+    parser: NetstringParser,
+}
+
+impl<R: Read> NetstringReader<R> {
+    /// Creates a new reader wrapping `inner`, with an internal buffer of `buf_size` bytes.
+    pub fn new(inner: R, buf_size: usize) -> Self {
+        Self {
+            inner,
+            parser: NetstringParser::new(buf_size),
+        }
+    }
+
+    /// Reads the next complete netstring from the underlying reader.
+    ///
+    /// Returns `Ok(None)` on a clean EOF with no partial frame buffered.
+    pub fn read_netstring(&mut self) -> io::Result<Option<Vec<u8>>> {
+        loop {
+            if let Some(ns) = self.parser.parse_next().map_err(to_io_error)? {
+                return Ok(Some(ns.to_vec()));
+            }
+
+            let buf = self.parser.available_buffer();
+            if buf.is_empty() {
+                return Err(to_io_error(NetstringError::StringTooLong));
+            }
+
+            let n = self.inner.read(buf)?;
+            if n == 0 {
+                return if self.parser.is_buffer_empty() {
+                    Ok(None)
+                } else {
+                    Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "connection closed mid-netstring",
+                    ))
+                };
+            }
+            self.parser.advance(n);
+        }
+    }
+}
+
+fn to_io_error(err: NetstringError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+/// Writes netstrings to an underlying [`Write`] implementation.
+#[derive(Debug)]
+pub struct NetstringWriter<W> {
+    inner: W,
+}
+
+impl<W: Write> NetstringWriter<W> {
+    /// Creates a new writer wrapping `inner`.
+    pub fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Encodes `data` as a netstring and writes it to the underlying writer.
+    ///
+    /// This writes the `len:` prefix, `data`, and the trailing `,` as separate
+    /// writes instead of [`encode`](crate::encode)ing into a temporary buffer first,
+    /// so no allocation is needed regardless of `data`'s size. For small payloads
+    /// that fit `buf`, it's encoded via [`NetstringEncoder`] and written in one go.
+    pub fn write_netstring(&mut self, data: &[u8]) -> io::Result<()> {
+        let encoder = NetstringEncoder::new(data);
+        let mut buf = [0u8; 256];
+        if let Ok(written) = encoder.write_to(&mut buf) {
+            return self.inner.write_all(&buf[..written]);
+        }
+
+        let mut prefix = [0u8; 20]; // fits the decimal digits of any usize
+        let prefix_len = crate::digits(data.len());
+        crate::write_decimal(data.len(), &mut prefix[..prefix_len]);
+        self.inner.write_all(&prefix[..prefix_len])?;
+        self.inner.write_all(b":")?;
+        self.inner.write_all(data)?;
+        self.inner.write_all(b",")
+    }
+
+    /// Flushes the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
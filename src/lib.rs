@@ -1,3 +1,4 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![doc = include_str!("../README.md")]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![deny(
@@ -20,26 +21,68 @@
     unused_results
 )]
 #![allow(clippy::uninlined_format_args)]
-use std::{ops::Deref, str::Utf8Error};
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::ToString, vec, vec::Vec};
+use core::{ops::Deref, str::Utf8Error};
+
+// `Error` below derives `thiserror::Error`, which implements `core::error::Error`
+// rather than `std::error::Error` only when thiserror itself is built without its
+// own default features (`thiserror = { version = "2", default-features = false }`
+// in Cargo.toml). Without that, this crate's `no_std` (no "std" feature) build
+// fails to compile.
 use thiserror::Error;
 
+#[cfg(feature = "std")]
+pub mod io;
+
 /// A parser for **netstrings** (length-prefixed strings of the form `len:data,`).
 ///
 /// This parser maintains an internal buffer of received bytes. You can append
 /// data to the buffer, parse complete netstrings, and discard processed data.
+///
+/// This variant stores its buffer in a heap-allocated [`Vec<u8>`] and requires
+/// the `alloc` feature. For a `no_std`, no-allocation variant see
+/// [`FixedNetstringParser`].
+#[cfg(feature = "alloc")]
 #[derive(Debug)]
 pub struct NetstringParser {
     buf: Vec<u8>,
     len: usize,
+    max_length: Option<usize>,
+    growable: bool,
 }
 
+#[cfg(feature = "alloc")]
 impl NetstringParser {
     /// Creates a new parser with a buffer of the given size.
+    ///
+    /// The maximum accepted netstring length defaults to the largest payload
+    /// whose framed form (`len:payload,`) fits in `buf_size` bytes, unless
+    /// [`growable`](Self::growable) is enabled, in which case it is unbounded
+    /// by default. Use [`with_max_length`] to set an explicit bound.
+    ///
+    /// [`with_max_length`]: Self::with_max_length
     pub fn new(buf_size: usize) -> Self {
         Self {
             buf: vec![0; buf_size],
             len: 0,
+            max_length: None,
+            growable: false,
+        }
+    }
+
+    /// Returns the effective maximum accepted netstring length: the explicit
+    /// value set via [`with_max_length`](Self::with_max_length) if any,
+    /// otherwise the default described there.
+    fn effective_max_length(&self) -> usize {
+        match self.max_length {
+            Some(max_length) => max_length,
+            None if self.growable => usize::MAX,
+            None => max_payload_len(self.buf.len()),
         }
     }
 
@@ -82,14 +125,10 @@ impl NetstringParser {
     /// [`available_buffer`]: Self::available_buffer
     /// [`advance`]: Self::advance
     pub fn write(&mut self, data: &[u8]) -> Result<(), WriteError> {
-        let remaining = self.buf.len() - self.len;
-        if data.len() <= remaining {
-            self.buf[self.len..self.len + data.len()].copy_from_slice(data);
-            self.len += data.len();
-            Ok(())
-        } else {
-            Err(WriteError::BufferTooSmall)
-        }
+        self.ensure_capacity(data.len())?;
+        self.buf[self.len..self.len + data.len()].copy_from_slice(data);
+        self.len += data.len();
+        Ok(())
     }
 
     /// Returns true if the internal buffer is full.
@@ -105,12 +144,25 @@ impl NetstringParser {
     /// Attempts to parse the next complete netstring from the buffer.
     ///
     /// Returns `Ok(Some(Netstring))` if a full netstring is available, `Ok(None)` if
-    /// more data is needed, or an error if the data is malformed.
+    /// more data is needed, or an error if the data is malformed or declares a length
+    /// greater than [`max_length`](Self::with_max_length).
     pub fn parse_next<'a>(&'a mut self) -> Result<Option<Netstring<'a>>, NetstringError> {
         match parse_length(&self.buf[..self.len])? {
             None => Ok(None),
             Some((len, rest)) => {
+                if len > self.effective_max_length() {
+                    return Err(NetstringError::StringTooLong);
+                }
                 if rest.len() < len + 1 {
+                    let offset = self.len - rest.len();
+                    let needed = offset + len + 1;
+                    if needed > self.buf.len() && self.ensure_capacity(needed - self.len).is_err()
+                    {
+                        // The frame can never fit: the buffer isn't growable (or
+                        // growing it would exceed `max_length`), so waiting for more
+                        // data would block forever.
+                        return Err(NetstringError::StringTooLong);
+                    }
                     return Ok(None); // need more data
                 }
                 if rest[len] != b',' {
@@ -126,11 +178,81 @@ impl NetstringParser {
         }
     }
 
+    /// Returns an iterator that yields each complete netstring currently buffered.
+    ///
+    /// Iteration stops (returning `None`) once no complete netstring is available,
+    /// or after yielding `Some(Err(_))` for a malformed frame. Frames are copied out
+    /// as owned [`Vec<u8>`]s, so unlike [`parse_next`](Self::parse_next) you aren't
+    /// limited to holding one [`Netstring`] borrow at a time.
+    ///
+    /// # Example
+    /// ```rust
+    /// use netstring_parser::NetstringParser;
+    ///
+    /// let mut parser = NetstringParser::new(32);
+    /// parser.write(b"5:hello,5:world,").unwrap();
+    ///
+    /// let frames: Result<Vec<_>, _> = parser.drain().collect();
+    /// assert_eq!(frames.unwrap(), vec![b"hello".to_vec(), b"world".to_vec()]);
+    /// ```
+    pub fn drain(&mut self) -> NetstringDrain<'_> {
+        NetstringDrain {
+            parser: self,
+            done: false,
+        }
+    }
+
     /// Clears the parser, discarding all buffered data.
     pub fn clear(&mut self) {
         self.len = 0;
     }
 
+    /// Sets the maximum accepted netstring length.
+    ///
+    /// If a peer declares a length greater than this, [`parse_next`](Self::parse_next)
+    /// fails eagerly with [`NetstringError::StringTooLong`] instead of buffering
+    /// indefinitely while waiting for data that will never fit. See [`new`](Self::new)
+    /// for the default when this isn't called.
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Allows the internal buffer to grow (up to [`max_length`](Self::with_max_length))
+    /// instead of failing with [`WriteError::BufferTooSmall`] when it doesn't have
+    /// room for incoming data. Disabled by default.
+    ///
+    /// If [`with_max_length`](Self::with_max_length) is never called, enabling this
+    /// also removes the default length cap, since that default only exists to make
+    /// a non-growable buffer fail fast: a growable parser can just grow instead.
+    /// Call `with_max_length` afterwards to bound growth explicitly.
+    pub fn growable(mut self, growable: bool) -> Self {
+        self.growable = growable;
+        self
+    }
+
+    /// Ensures the internal buffer has room for at least `additional` more bytes,
+    /// growing it (up to the framed size of a `max_length`-byte payload) if this
+    /// parser is [`growable`](Self::growable).
+    fn ensure_capacity(&mut self, additional: usize) -> Result<(), WriteError> {
+        let needed = self.len + additional;
+        if needed <= self.buf.len() {
+            return Ok(());
+        }
+        // The buffer must hold the whole framed message (`len:payload,`), not just
+        // the payload, so a payload of exactly `max_length` bytes still fits.
+        let buffer_cap = framed_len(self.effective_max_length());
+        if !self.growable || needed > buffer_cap {
+            return Err(WriteError::BufferTooSmall);
+        }
+        let mut new_size = self.buf.len().max(1);
+        while new_size < needed {
+            new_size = new_size.saturating_mul(2);
+        }
+        self.buf.resize(new_size.min(buffer_cap), 0);
+        Ok(())
+    }
+
     /// Discards the first `count` bytes from the buffer.
     ///
     /// Internal helper used by [`Netstring`] when a netstring is dropped.
@@ -168,20 +290,152 @@ pub enum WriteError {
     BufferTooSmall,
 }
 
+/// This error is returned by [`NetstringEncoder::write_to`].
+#[derive(Debug, Error, Copy, Clone)]
+pub enum EncodeError {
+    /// Output buffer is too small to hold the encoded netstring.
+    #[error("Buffer too small")]
+    BufferTooSmall,
+}
+
+/// Encodes `data` as a netstring (`<length>:<data>,`) and appends the result to `out`.
+///
+/// This is the inverse of [`NetstringParser::parse_next`].
+///
+/// # Example
+/// ```rust
+/// use netstring_parser::encode;
+///
+/// let mut out = Vec::new();
+/// encode(b"hello", &mut out);
+/// assert_eq!(out, b"5:hello,");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode(data: &[u8], out: &mut Vec<u8>) {
+    out.extend_from_slice(data.len().to_string().as_bytes());
+    out.push(b':');
+    out.extend_from_slice(data);
+    out.push(b',');
+}
+
+/// Encodes `data` as a netstring and returns the result as a newly allocated [`Vec<u8>`].
+///
+/// # Example
+/// ```rust
+/// use netstring_parser::encode_to_vec;
+///
+/// assert_eq!(encode_to_vec(b"hello"), b"5:hello,");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode_to_vec(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(framed_len(data.len()));
+    encode(data, &mut out);
+    out
+}
+
+/// Encodes a single netstring into a caller-provided buffer without allocating.
+///
+/// This is the buffer-based counterpart to [`NetstringParser`]: instead of
+/// parsing `len:data,` out of a buffer, it writes `len:data,` into one. Unlike
+/// [`encode`] and [`encode_to_vec`], this works without `alloc`.
+#[derive(Debug, Clone, Copy)]
+pub struct NetstringEncoder<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> NetstringEncoder<'a> {
+    /// Creates a new encoder for the given payload.
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    /// Returns the number of bytes the encoded netstring will occupy.
+    pub fn encoded_len(&self) -> usize {
+        framed_len(self.data.len())
+    }
+
+    /// Writes the encoded netstring into `out`, returning the number of bytes written.
+    ///
+    /// Returns [`EncodeError::BufferTooSmall`] (and leaves `out` untouched) if `out`
+    /// is not large enough to hold the encoded netstring.
+    pub fn write_to(&self, out: &mut [u8]) -> Result<usize, EncodeError> {
+        let needed = self.encoded_len();
+        if out.len() < needed {
+            return Err(EncodeError::BufferTooSmall);
+        }
+        let len_digits = digits(self.data.len());
+        let mut pos = 0;
+        write_decimal(self.data.len(), &mut out[pos..pos + len_digits]);
+        pos += len_digits;
+        out[pos] = b':';
+        pos += 1;
+        out[pos..pos + self.data.len()].copy_from_slice(self.data);
+        pos += self.data.len();
+        out[pos] = b',';
+        pos += 1;
+        Ok(pos)
+    }
+}
+
+/// Returns the number of decimal digits needed to print `n` (at least one).
+fn digits(mut n: usize) -> usize {
+    let mut count = 1;
+    while n >= 10 {
+        n /= 10;
+        count += 1;
+    }
+    count
+}
+
+/// Returns the number of bytes needed to frame a `payload_len`-byte payload as
+/// a netstring: the decimal length prefix, the colon, the payload itself, and
+/// the trailing comma.
+fn framed_len(payload_len: usize) -> usize {
+    digits(payload_len)
+        .saturating_add(2)
+        .saturating_add(payload_len)
+}
+
+/// Writes the decimal digits of `n` into `out`, most significant digit first.
+///
+/// `out` must be exactly `digits(n)` bytes long.
+fn write_decimal(mut n: usize, out: &mut [u8]) {
+    for i in (0..out.len()).rev() {
+        out[i] = b'0' + (n % 10) as u8;
+        n /= 10;
+    }
+}
+
+/// Returns the largest netstring payload length whose framed form (`len:payload,`)
+/// fits within `capacity` bytes, or `0` if even an empty payload wouldn't fit.
+fn max_payload_len(capacity: usize) -> usize {
+    if capacity < 3 {
+        // An empty payload still needs "0:" + "," = 3 bytes to frame.
+        return 0;
+    }
+    let mut len = capacity - 2;
+    while framed_len(len) > capacity {
+        len -= 1;
+    }
+    len
+}
+
 /// A parsed netstring slice.
 ///
 /// Automatically discards the underlying bytes when dropped.
+#[cfg(feature = "alloc")]
 pub struct Netstring<'a> {
     parser: &'a mut NetstringParser,
     offset: usize,
     length: usize,
 }
 
+#[cfg(feature = "alloc")]
 impl Netstring<'_> {
     /// Converts the netstring which consists of a slice of bytes
     /// to a string slice.
     pub fn to_str(&self) -> Result<&str, Utf8Error> {
-        std::str::from_utf8(self)
+        core::str::from_utf8(self)
     }
     /// Get netstring as byte slice.
     pub fn as_bytes(&self) -> &[u8] {
@@ -189,14 +443,16 @@ impl Netstring<'_> {
     }
 }
 
-impl<'a> std::fmt::Debug for Netstring<'a> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+#[cfg(feature = "alloc")]
+impl<'a> core::fmt::Debug for Netstring<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Netstring").field(&self.as_bytes()).finish()
     }
 }
 
-impl<'a> std::fmt::Display for Netstring<'a> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+#[cfg(feature = "alloc")]
+impl<'a> core::fmt::Display for Netstring<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self.to_str() {
             Ok(s) => f.write_str(s),
             Err(_) => write!(f, "<invalid utf-8: {:?}>", self.as_bytes()),
@@ -204,6 +460,7 @@ impl<'a> std::fmt::Display for Netstring<'a> {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'a> Deref for Netstring<'a> {
     type Target = [u8];
     fn deref(&self) -> &Self::Target {
@@ -211,6 +468,7 @@ impl<'a> Deref for Netstring<'a> {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'a> Drop for Netstring<'a> {
     fn drop(&mut self) {
         // Consume the netstring including the trailing comma
@@ -218,6 +476,234 @@ impl<'a> Drop for Netstring<'a> {
     }
 }
 
+/// Iterator over complete netstrings buffered in a [`NetstringParser`].
+///
+/// Created by [`NetstringParser::drain`].
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct NetstringDrain<'a> {
+    parser: &'a mut NetstringParser,
+    done: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl Iterator for NetstringDrain<'_> {
+    type Item = Result<Vec<u8>, NetstringError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.parser.parse_next() {
+            Ok(Some(ns)) => Some(Ok(ns.to_vec())),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+/// A [`NetstringParser`] that stores its buffer inline as `[u8; N]` instead of
+/// allocating a [`Vec<u8>`].
+///
+/// This shares [`NetstringParser`]'s `available_buffer`/`advance`/`parse_next`/`clear`
+/// surface but never allocates, so it works in `no_std` environments without `alloc`,
+/// such as microcontrollers.
+#[derive(Debug)]
+pub struct FixedNetstringParser<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+    max_length: Option<usize>,
+}
+
+impl<const N: usize> FixedNetstringParser<N> {
+    /// Creates a new parser with a zeroed, stack-allocated buffer of `N` bytes.
+    ///
+    /// The maximum accepted netstring length defaults to the largest payload
+    /// whose framed form (`len:payload,`) fits in `N` bytes; use
+    /// [`with_max_length`] to lower it further.
+    ///
+    /// [`with_max_length`]: Self::with_max_length
+    pub fn new() -> Self {
+        Self {
+            buf: [0; N],
+            len: 0,
+            max_length: None,
+        }
+    }
+
+    /// Returns the effective maximum accepted netstring length: the explicit
+    /// value set via [`with_max_length`](Self::with_max_length) if any,
+    /// otherwise the default described there.
+    fn effective_max_length(&self) -> usize {
+        self.max_length.unwrap_or_else(|| max_payload_len(N))
+    }
+
+    /// Returns a mutable slice of the unused portion of the internal buffer.
+    ///
+    /// You can write data directly into this slice. After writing, you **must**
+    /// call [`advance`](Self::advance) with the number of bytes actually written
+    /// to update the parser's internal length.
+    pub fn available_buffer(&mut self) -> &mut [u8] {
+        &mut self.buf[self.len..]
+    }
+
+    /// Advances the internal buffer position by `count` bytes.
+    ///
+    /// This method **must** be called after writing to the slice returned by
+    /// [`available_buffer`](Self::available_buffer) to update the parser state.
+    pub fn advance(&mut self, count: usize) {
+        self.len += count;
+    }
+
+    /// Writes data into the parser's internal buffer.
+    pub fn write(&mut self, data: &[u8]) -> Result<(), WriteError> {
+        let remaining = N - self.len;
+        if data.len() > remaining {
+            return Err(WriteError::BufferTooSmall);
+        }
+        self.buf[self.len..self.len + data.len()].copy_from_slice(data);
+        self.len += data.len();
+        Ok(())
+    }
+
+    /// Returns true if the internal buffer is full.
+    pub fn is_buffer_full(&self) -> bool {
+        self.len >= N
+    }
+
+    /// Returns true if the internal buffer is empty.
+    pub fn is_buffer_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Attempts to parse the next complete netstring from the buffer.
+    ///
+    /// Returns `Ok(Some(FixedNetstring))` if a full netstring is available, `Ok(None)`
+    /// if more data is needed, or an error if the data is malformed or declares a
+    /// length greater than [`max_length`](Self::with_max_length).
+    pub fn parse_next<'a>(&'a mut self) -> Result<Option<FixedNetstring<'a, N>>, NetstringError> {
+        match parse_length(&self.buf[..self.len])? {
+            None => Ok(None),
+            Some((len, rest)) => {
+                if len > self.effective_max_length() {
+                    return Err(NetstringError::StringTooLong);
+                }
+                if rest.len() < len + 1 {
+                    let offset = self.len - rest.len();
+                    if offset + len + 1 > N {
+                        // The buffer can never hold the full frame (prefix + payload +
+                        // trailing comma), so waiting for more data would block forever.
+                        return Err(NetstringError::StringTooLong);
+                    }
+                    return Ok(None); // need more data
+                }
+                if rest[len] != b',' {
+                    return Err(NetstringError::MissingComma);
+                }
+                let offset = self.len - rest.len();
+                Ok(Some(FixedNetstring {
+                    parser: self,
+                    offset,
+                    length: len,
+                }))
+            }
+        }
+    }
+
+    /// Clears the parser, discarding all buffered data.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Sets the maximum accepted netstring length.
+    ///
+    /// See [`new`](Self::new) for the default when this isn't called. Note that
+    /// a payload length alone isn't enough to hold the full frame: the encoded
+    /// form also needs room for the `len:` prefix and trailing `,`. Setting a
+    /// value whose framed form exceeds `N` doesn't error here — [`parse_next`]
+    /// still accepts the declared length eagerly, but then fails with
+    /// [`NetstringError::StringTooLong`] once it's clear the frame can never
+    /// complete, since the buffer can never hold more than `N` bytes.
+    ///
+    /// [`parse_next`]: Self::parse_next
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Discards the first `count` bytes from the buffer.
+    ///
+    /// Internal helper used by [`FixedNetstring`] when a netstring is dropped.
+    fn discard(&mut self, count: usize) {
+        self.buf.copy_within(count..self.len, 0);
+        self.len = self.len.saturating_sub(count);
+    }
+}
+
+impl<const N: usize> Default for FixedNetstringParser<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A parsed netstring slice borrowed from a [`FixedNetstringParser`].
+///
+/// Automatically discards the underlying bytes when dropped.
+pub struct FixedNetstring<'a, const N: usize> {
+    parser: &'a mut FixedNetstringParser<N>,
+    offset: usize,
+    length: usize,
+}
+
+impl<const N: usize> FixedNetstring<'_, N> {
+    /// Converts the netstring which consists of a slice of bytes
+    /// to a string slice.
+    pub fn to_str(&self) -> Result<&str, Utf8Error> {
+        core::str::from_utf8(self)
+    }
+    /// Get netstring as byte slice.
+    pub fn as_bytes(&self) -> &[u8] {
+        self
+    }
+}
+
+impl<const N: usize> core::fmt::Debug for FixedNetstring<'_, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("FixedNetstring")
+            .field(&self.as_bytes())
+            .finish()
+    }
+}
+
+impl<const N: usize> core::fmt::Display for FixedNetstring<'_, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.to_str() {
+            Ok(s) => f.write_str(s),
+            Err(_) => write!(f, "<invalid utf-8: {:?}>", self.as_bytes()),
+        }
+    }
+}
+
+impl<const N: usize> Deref for FixedNetstring<'_, N> {
+    type Target = [u8];
+    fn deref(&self) -> &Self::Target {
+        &self.parser.buf[self.offset..self.offset + self.length]
+    }
+}
+
+impl<const N: usize> Drop for FixedNetstring<'_, N> {
+    fn drop(&mut self) {
+        // Consume the netstring including the trailing comma
+        self.parser.discard(self.offset + self.length + 1);
+    }
+}
+
 fn parse_length(input: &[u8]) -> Result<Option<(usize, &[u8])>, NetstringError> {
     let Some(colon_pos) = input.iter().position(|&b| b == b':') else {
         if input.len() > 20 {
@@ -231,7 +717,7 @@ fn parse_length(input: &[u8]) -> Result<Option<(usize, &[u8])>, NetstringError>
     };
     let len = &input[..colon_pos];
     let rest = &input[colon_pos + 1..];
-    let Ok(len) = std::str::from_utf8(len) else {
+    let Ok(len) = core::str::from_utf8(len) else {
         return Err(NetstringError::InvalidLength);
     };
     let Ok(len) = len.parse::<usize>() else {
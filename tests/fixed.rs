@@ -0,0 +1,103 @@
+use netstring_parser::{FixedNetstringParser, NetstringEncoder, NetstringError, WriteError};
+
+#[test]
+fn parse_simple_netstring() {
+    let mut parser = FixedNetstringParser::<32>::new();
+
+    parser.write(b"5:hello,").unwrap();
+
+    {
+        let ns = parser.parse_next().unwrap().unwrap();
+        assert_eq!(&*ns, b"hello");
+    }
+
+    assert!(parser.parse_next().unwrap().is_none());
+}
+
+#[test]
+fn parse_multiple_netstrings_in_chunks() {
+    let mut parser = FixedNetstringParser::<32>::new();
+
+    let chunks: &[&[u8]] = &[b"5:he", b"llo,5:w", b"orld,3:by", b"e,"];
+    let expected: &[&[u8]] = &[b"hello", b"world", b"bye"];
+    let mut results = Vec::new();
+
+    for chunk in chunks {
+        parser.write(chunk).unwrap();
+
+        while let Some(ns) = parser.parse_next().unwrap() {
+            results.push(ns.to_vec());
+        }
+    }
+
+    assert_eq!(results.len(), expected.len());
+    for (res, &exp) in results.iter().zip(expected) {
+        assert_eq!(res.as_slice(), exp);
+    }
+    assert!(parser.is_buffer_empty());
+}
+
+#[test]
+fn write_buffer_too_small() {
+    let mut parser = FixedNetstringParser::<5>::new();
+    let result = parser.write(b"123456");
+    assert!(matches!(result, Err(WriteError::BufferTooSmall)));
+}
+
+#[test]
+fn invalid_length_error() {
+    let mut parser = FixedNetstringParser::<32>::new();
+    parser.write(b"x:bad,").unwrap();
+    let err = parser.parse_next().unwrap_err();
+    assert!(matches!(err, NetstringError::InvalidLength));
+}
+
+#[test]
+fn max_length_rejects_oversized_frame_eagerly() {
+    let mut parser = FixedNetstringParser::<32>::new().with_max_length(10);
+
+    parser.write(b"999:").unwrap();
+    let err = parser.parse_next().unwrap_err();
+    assert!(matches!(err, NetstringError::StringTooLong));
+}
+
+#[test]
+fn max_length_is_capped_by_buffer_size() {
+    let mut parser = FixedNetstringParser::<8>::new().with_max_length(1000);
+
+    parser.write(b"999:").unwrap();
+    let err = parser.parse_next().unwrap_err();
+    assert!(matches!(err, NetstringError::StringTooLong));
+}
+
+#[test]
+fn default_max_length_accounts_for_framing_overhead() {
+    // A declared length of 8 fits in an 8-byte buffer on its own, but the full
+    // frame "8:AAAAAAAA," (11 bytes) never can. This must fail eagerly instead
+    // of sitting at `Ok(None)` forever.
+    let mut parser = FixedNetstringParser::<8>::new();
+
+    parser.write(b"8:AAAAAA").unwrap();
+    let err = parser.parse_next().unwrap_err();
+    assert!(matches!(err, NetstringError::StringTooLong));
+}
+
+#[test]
+fn netstring_encoder_write_to_without_alloc() {
+    // NetstringEncoder writes into a caller-owned, fixed-size buffer, so it
+    // works anywhere FixedNetstringParser does, without allocating.
+    let encoder = NetstringEncoder::new(b"hello");
+    let mut buf = [0u8; 8];
+    let written = encoder.write_to(&mut buf).unwrap();
+    assert_eq!(written, 8);
+    assert_eq!(&buf, b"5:hello,");
+}
+
+#[test]
+fn default_matches_new() {
+    let mut parser = FixedNetstringParser::<16>::default();
+    assert!(parser.is_buffer_empty());
+    parser.write(b"2:hi,").unwrap();
+    let ns = parser.parse_next().unwrap().unwrap();
+    assert_eq!(&*ns, b"hi");
+}
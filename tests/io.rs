@@ -0,0 +1,55 @@
+use std::io::Cursor;
+
+use netstring_parser::io::{NetstringReader, NetstringWriter};
+
+#[test]
+fn reader_reads_frames_across_short_reads() {
+    let data = b"5:hello,5:world,".to_vec();
+    let mut reader = NetstringReader::new(Cursor::new(data), 8);
+
+    assert_eq!(reader.read_netstring().unwrap(), Some(b"hello".to_vec()));
+    assert_eq!(reader.read_netstring().unwrap(), Some(b"world".to_vec()));
+    assert_eq!(reader.read_netstring().unwrap(), None);
+}
+
+#[test]
+fn reader_errors_on_malformed_frame() {
+    let mut reader = NetstringReader::new(Cursor::new(b"x:bad,".to_vec()), 32);
+    assert!(reader.read_netstring().is_err());
+}
+
+#[test]
+fn reader_errors_on_truncated_frame() {
+    let mut reader = NetstringReader::new(Cursor::new(b"5:hel".to_vec()), 32);
+    assert!(reader.read_netstring().is_err());
+}
+
+#[test]
+fn writer_writes_encoded_frame() {
+    let mut out = Vec::new();
+    {
+        let mut writer = NetstringWriter::new(&mut out);
+        writer.write_netstring(b"hello").unwrap();
+        writer.write_netstring(b"world").unwrap();
+    }
+    assert_eq!(out, b"5:hello,5:world,");
+}
+
+#[test]
+fn writer_writes_payload_larger_than_its_stack_buffer() {
+    let payload = vec![b'x'; 1000];
+    let mut out = Vec::new();
+    NetstringWriter::new(&mut out).write_netstring(&payload).unwrap();
+
+    let mut reader = NetstringReader::new(Cursor::new(out), 1024);
+    assert_eq!(reader.read_netstring().unwrap(), Some(payload));
+}
+
+#[test]
+fn writer_and_reader_roundtrip() {
+    let mut buf = Vec::new();
+    NetstringWriter::new(&mut buf).write_netstring(b"hello").unwrap();
+
+    let mut reader = NetstringReader::new(Cursor::new(buf), 32);
+    assert_eq!(reader.read_netstring().unwrap(), Some(b"hello".to_vec()));
+}
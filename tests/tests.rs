@@ -1,4 +1,7 @@
-use netstring_parser::{NetstringError, NetstringParser, WriteError};
+use netstring_parser::{
+    encode, encode_to_vec, EncodeError, NetstringEncoder, NetstringError, NetstringParser,
+    WriteError,
+};
 
 #[test]
 fn parse_simple_netstring() {
@@ -127,3 +130,186 @@ fn parse_multiple_netstrings_in_chunks() {
     assert!(parser.parse_next().unwrap().is_none());
     assert!(parser.is_buffer_empty());
 }
+
+#[test]
+fn encode_writes_length_prefixed_frame() {
+    let mut out = Vec::new();
+    encode(b"hello", &mut out);
+    assert_eq!(out, b"5:hello,");
+}
+
+#[test]
+fn encode_to_vec_returns_encoded_frame() {
+    assert_eq!(encode_to_vec(b"hello"), b"5:hello,");
+    assert_eq!(encode_to_vec(b""), b"0:,");
+}
+
+#[test]
+fn encode_then_parse_roundtrip() {
+    let mut parser = NetstringParser::new(32);
+    parser.write(&encode_to_vec(b"hello")).unwrap();
+
+    let ns = parser.parse_next().unwrap().unwrap();
+    assert_eq!(&*ns, b"hello");
+}
+
+#[test]
+fn netstring_encoder_write_to() {
+    let encoder = NetstringEncoder::new(b"hello");
+    assert_eq!(encoder.encoded_len(), 8);
+
+    let mut buf = [0u8; 8];
+    let written = encoder.write_to(&mut buf).unwrap();
+    assert_eq!(written, 8);
+    assert_eq!(&buf, b"5:hello,");
+}
+
+#[test]
+fn max_length_rejects_oversized_frame_eagerly() {
+    let mut parser = NetstringParser::new(32).with_max_length(10);
+
+    parser.write(b"999:").unwrap();
+    let err = parser.parse_next().unwrap_err();
+    assert!(matches!(err, NetstringError::StringTooLong));
+}
+
+#[test]
+fn default_max_length_rejects_length_beyond_buffer() {
+    let mut parser = NetstringParser::new(8);
+
+    // A declared length that would never fit the buffer fails eagerly instead
+    // of silently waiting for data that can never arrive.
+    parser.write(b"999999:").unwrap();
+    let err = parser.parse_next().unwrap_err();
+    assert!(matches!(err, NetstringError::StringTooLong));
+}
+
+#[test]
+fn default_max_length_accounts_for_framing_overhead() {
+    // A declared length of 8 fits in an 8-byte buffer on its own, but the full
+    // frame "8:AAAAAAAA," (11 bytes) never can. This must fail eagerly instead
+    // of sitting at `Ok(None)` forever.
+    let mut parser = NetstringParser::new(8);
+
+    parser.write(b"8:AAAAAA").unwrap();
+    let err = parser.parse_next().unwrap_err();
+    assert!(matches!(err, NetstringError::StringTooLong));
+}
+
+#[test]
+fn growable_parser_expands_to_fit_large_frame() {
+    let mut parser = NetstringParser::new(8).growable(true).with_max_length(1024);
+
+    parser.write(b"20:").unwrap();
+    parser.write(b"this is over 8 bytes").unwrap();
+    parser.write(b",").unwrap();
+
+    let ns = parser.parse_next().unwrap().unwrap();
+    assert_eq!(&*ns, b"this is over 8 bytes");
+}
+
+#[test]
+fn growable_alone_is_not_a_no_op() {
+    // Enabling `growable` without also calling `with_max_length` must still let
+    // the buffer grow; the default length cap only exists to fail fast on a
+    // non-growable parser and shouldn't block growth on its own.
+    let mut parser = NetstringParser::new(8).growable(true);
+
+    parser.write(b"20:").unwrap();
+    parser.write(b"this is over 8 bytes").unwrap();
+    parser.write(b",").unwrap();
+
+    let ns = parser.parse_next().unwrap().unwrap();
+    assert_eq!(&*ns, b"this is over 8 bytes");
+}
+
+#[test]
+fn growable_parser_accepts_payload_exactly_at_max_length() {
+    // The buffer must hold the framed bytes (digits + ':' + payload + ','), not
+    // just the payload, so a payload of exactly `max_length` bytes must still fit.
+    let payload = vec![b'x'; 1024];
+    let mut parser = NetstringParser::new(8).growable(true).with_max_length(1024);
+
+    parser.write(format!("{}:", payload.len()).as_bytes()).unwrap();
+    parser.write(&payload).unwrap();
+    parser.write(b",").unwrap();
+
+    let ns = parser.parse_next().unwrap().unwrap();
+    assert_eq!(&*ns, payload.as_slice());
+}
+
+#[test]
+fn growable_parser_still_honors_max_length() {
+    let mut parser = NetstringParser::new(8).growable(true).with_max_length(16);
+
+    let result = parser.write(b"this does not fit even after growing");
+    assert!(matches!(result, Err(WriteError::BufferTooSmall)));
+}
+
+#[test]
+fn non_growable_parser_keeps_erroring_on_overflow() {
+    let mut parser = NetstringParser::new(5);
+    let result = parser.write(b"123456");
+    assert!(matches!(result, Err(WriteError::BufferTooSmall)));
+}
+
+#[test]
+fn drain_yields_all_complete_frames() {
+    let mut parser = NetstringParser::new(32);
+    parser.write(b"5:hello,5:world,3:by").unwrap();
+
+    let frames: Result<Vec<_>, _> = parser.drain().collect();
+    assert_eq!(frames.unwrap(), vec![b"hello".to_vec(), b"world".to_vec()]);
+
+    // The trailing partial frame ("3:by") stays buffered for the next write.
+    assert!(!parser.is_buffer_empty());
+    parser.write(b"e,").unwrap();
+    let ns = parser.parse_next().unwrap().unwrap();
+    assert_eq!(&*ns, b"bye");
+}
+
+#[test]
+fn drain_stops_and_surfaces_error_on_malformed_frame() {
+    let mut parser = NetstringParser::new(32);
+    parser.write(b"5:hello,x:bad,").unwrap();
+
+    let mut drain = parser.drain();
+    assert_eq!(drain.next().unwrap().unwrap(), b"hello");
+    assert!(matches!(drain.next(), Some(Err(NetstringError::InvalidLength))));
+    assert!(drain.next().is_none());
+}
+
+#[test]
+fn drain_over_chunked_input_matches_manual_loop() {
+    let mut parser = NetstringParser::new(32);
+
+    let chunks: &[&[u8]] = &[
+        b"5:he",
+        b"llo,5:w",
+        b"orld,3:by",
+        b"e,",
+    ];
+    let expected: &[&[u8]] = &[b"hello", b"world", b"bye"];
+    let mut results = Vec::new();
+
+    for chunk in chunks {
+        parser.write(chunk).unwrap();
+        for ns in parser.drain() {
+            results.push(ns.unwrap());
+        }
+    }
+
+    assert_eq!(results.len(), expected.len());
+    for (res, &exp) in results.iter().zip(expected) {
+        assert_eq!(res.as_slice(), exp);
+    }
+    assert!(parser.is_buffer_empty());
+}
+
+#[test]
+fn netstring_encoder_buffer_too_small() {
+    let encoder = NetstringEncoder::new(b"hello");
+    let mut buf = [0u8; 7];
+    let result = encoder.write_to(&mut buf);
+    assert!(matches!(result, Err(EncodeError::BufferTooSmall)));
+}